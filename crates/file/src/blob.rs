@@ -25,7 +25,7 @@ fn from_u128(number: u128) -> f64 {
     number as f64
 }
 
-fn blob_slice(blob: &web_sys::Blob, start: u64, end: u64) -> web_sys::Blob {
+pub(crate) fn blob_slice(blob: &web_sys::Blob, start: u64, end: u64) -> web_sys::Blob {
     let start = from_u64(start);
     let end = from_u64(end);
     blob.slice_with_f64_and_f64(start, end).unwrap_throw()
@@ -250,3 +250,46 @@ impl BlobLike for File {
         )
     }
 }
+
+/// A wrapper around an object URL created with `URL.createObjectURL`.
+///
+/// The URL can be used anywhere a string is expected (e.g. the `src` of an
+/// `<img>` or a download link) via its [`Deref`](std::ops::Deref) and
+/// [`AsRef`] implementations, and is automatically released with
+/// `URL.revokeObjectURL` when the `ObjectUrl` is dropped.
+#[derive(Debug)]
+pub struct ObjectUrl {
+    url: String,
+}
+
+impl ObjectUrl {
+    pub fn new<B>(blob: &B) -> ObjectUrl
+    where
+        B: BlobLike,
+    {
+        let url = web_sys::Url::create_object_url_with_blob(blob.as_raw()).unwrap_throw();
+        ObjectUrl { url }
+    }
+}
+
+impl std::ops::Deref for ObjectUrl {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.url
+    }
+}
+
+impl AsRef<str> for ObjectUrl {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        &self.url
+    }
+}
+
+impl Drop for ObjectUrl {
+    fn drop(&mut self) {
+        let _ = web_sys::Url::revoke_object_url(&self.url);
+    }
+}
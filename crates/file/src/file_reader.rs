@@ -15,8 +15,11 @@ impl std::error::Error for FileReadError {}
 pub mod callbacks {
     use super::FileReadError;
     use crate::blob::BlobLike;
+    use crate::blob::blob_slice;
     use gloo_events::EventListener;
-    use wasm_bindgen::{JsValue, UnwrapThrowExt};
+    use std::cell::RefCell;
+    use std::rc::{Rc, Weak};
+    use wasm_bindgen::{JsCast, JsValue, UnwrapThrowExt};
 
     fn get_result(reader: &web_sys::FileReader) -> Result<JsValue, FileReadError> {
         if let Some(error) = reader.error() {
@@ -34,6 +37,7 @@ pub mod callbacks {
     pub struct FileReader {
         reader: web_sys::FileReader,
         on_loadend: Option<EventListener>,
+        on_progress: Option<EventListener>,
     }
 
     fn read_as<R, F>(blob: &web_sys::Blob, read: R, callback: F) -> FileReader
@@ -52,7 +56,45 @@ pub mod callbacks {
 
         read(&reader, blob).unwrap_throw();
 
-        FileReader { reader, on_loadend }
+        FileReader {
+            reader,
+            on_loadend,
+            on_progress: None,
+        }
+    }
+
+    fn read_as_with_progress<R, F, P>(
+        blob: &web_sys::Blob,
+        read: R,
+        mut on_progress: P,
+        callback: F,
+    ) -> FileReader
+    where
+        R: Fn(&web_sys::FileReader, &web_sys::Blob) -> Result<(), JsValue>,
+        F: FnOnce(Result<JsValue, FileReadError>) + 'static,
+        P: FnMut(u64, u64) + 'static,
+    {
+        let reader = web_sys::FileReader::new().unwrap_throw();
+
+        let on_loadend = Some(EventListener::once(&reader, "loadend", {
+            let reader = reader.clone();
+            move |_| {
+                callback(get_result(&reader));
+            }
+        }));
+
+        let on_progress = Some(EventListener::new(&reader, "progress", move |event| {
+            let event = event.unchecked_ref::<web_sys::ProgressEvent>();
+            on_progress(event.loaded() as u64, event.total() as u64);
+        }));
+
+        read(&reader, blob).unwrap_throw();
+
+        FileReader {
+            reader,
+            on_loadend,
+            on_progress,
+        }
     }
 
     #[inline]
@@ -92,13 +134,385 @@ pub mod callbacks {
         )
     }
 
+    #[inline]
+    pub fn read_to_string_with_progress<B, P, F>(
+        blob: &B,
+        on_progress: P,
+        callback: F,
+    ) -> FileReader
+    where
+        B: BlobLike,
+        P: FnMut(u64, u64) + 'static,
+        F: FnOnce(Result<String, FileReadError>) + 'static,
+    {
+        read_as_with_progress(
+            blob.as_raw(),
+            web_sys::FileReader::read_as_text,
+            on_progress,
+            move |x| callback(x.map(as_string)),
+        )
+    }
+
+    #[inline]
+    pub fn read_to_data_url_with_progress<B, P, F>(
+        blob: &B,
+        on_progress: P,
+        callback: F,
+    ) -> FileReader
+    where
+        B: BlobLike,
+        P: FnMut(u64, u64) + 'static,
+        F: FnOnce(Result<String, FileReadError>) + 'static,
+    {
+        read_as_with_progress(
+            blob.as_raw(),
+            web_sys::FileReader::read_as_data_url,
+            on_progress,
+            move |x| callback(x.map(as_string)),
+        )
+    }
+
+    #[inline]
+    pub fn read_to_array_buffer_with_progress<B, P, F>(
+        blob: &B,
+        on_progress: P,
+        callback: F,
+    ) -> FileReader
+    where
+        B: BlobLike,
+        P: FnMut(u64, u64) + 'static,
+        F: FnOnce(Result<js_sys::ArrayBuffer, FileReadError>) + 'static,
+    {
+        read_as_with_progress(
+            blob.as_raw(),
+            web_sys::FileReader::read_as_array_buffer,
+            on_progress,
+            move |x| callback(x.map(Into::into)),
+        )
+    }
+
     impl Drop for FileReader {
         fn drop(&mut self) {
             if self.reader.ready_state() != web_sys::FileReader::DONE {
-                // This is necessary to remove the EventListener so it isn't called by abort
+                // This is necessary to remove the EventListeners so they aren't called by abort
                 self.on_loadend.take();
+                self.on_progress.take();
+                self.reader.abort();
+            }
+        }
+    }
+
+    /// A handle to an in-progress chunked read started by [`read_chunks`].
+    ///
+    /// The read advances one chunk at a time: the next slice is never requested
+    /// until the previous `loadend` has fired, since a `FileReader` can only
+    /// perform one read at a time. Dropping the handle aborts the in-flight read
+    /// just like [`FileReader`].
+    #[derive(Debug)]
+    pub struct ChunkReader {
+        // The `Rc` is the only strong reference; the `loadend` listener holds a
+        // `Weak` to avoid a reference cycle that would leak the `FileReader`.
+        inner: Rc<RefCell<ChunkReaderInner>>,
+    }
+
+    struct ChunkReaderInner {
+        reader: web_sys::FileReader,
+        blob: web_sys::Blob,
+        size: u64,
+        offset: u64,
+        chunk_size: u64,
+        // `None` once the read has finished; dropping the closure releases any
+        // resources the consumer captured (e.g. a channel sender), which is how
+        // the `futures::ChunkStream` learns the read is over.
+        on_chunk: Option<Box<dyn FnMut(Result<js_sys::Uint8Array, FileReadError>) -> bool>>,
+        listener: Option<EventListener>,
+        done: bool,
+    }
+
+    impl std::fmt::Debug for ChunkReaderInner {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            f.debug_struct("ChunkReaderInner")
+                .field("reader", &self.reader)
+                .field("offset", &self.offset)
+                .field("size", &self.size)
+                .field("chunk_size", &self.chunk_size)
+                .field("done", &self.done)
+                .finish()
+        }
+    }
+
+    impl ChunkReaderInner {
+        fn read_next(&mut self) {
+            let end = std::cmp::min(self.offset + self.chunk_size, self.size);
+            let slice = blob_slice(&self.blob, self.offset, end);
+            self.reader.read_as_array_buffer(&slice).unwrap_throw();
+        }
+
+        fn stop(&mut self) {
+            self.done = true;
+            // Drop the consumer closure so it can signal end-of-stream (e.g. by
+            // closing the channel it captured), and drop the listener before
+            // aborting so it isn't called by `abort`.
+            self.on_chunk.take();
+            self.listener.take();
+            if self.reader.ready_state() != web_sys::FileReader::DONE {
+                self.reader.abort();
+            }
+        }
+
+        fn on_loadend(inner: &Rc<RefCell<ChunkReaderInner>>) {
+            let mut this = inner.borrow_mut();
+
+            if this.done {
+                return;
+            }
+
+            match get_result(&this.reader) {
+                Ok(buffer) => {
+                    let array = js_sys::Uint8Array::new(&buffer);
+                    let read = array.byte_length() as u64;
+
+                    let keep_going = match this.on_chunk.as_mut() {
+                        Some(on_chunk) => on_chunk(Ok(array)),
+                        None => false,
+                    };
+
+                    this.offset += read;
+
+                    // A zero-length read would otherwise spin forever.
+                    if !keep_going || read == 0 || this.offset >= this.size {
+                        this.stop();
+                    } else {
+                        this.read_next();
+                    }
+                }
+                Err(error) => {
+                    if let Some(on_chunk) = this.on_chunk.as_mut() {
+                        on_chunk(Err(error));
+                    }
+                    this.stop();
+                }
+            }
+        }
+    }
+
+    impl Drop for ChunkReaderInner {
+        fn drop(&mut self) {
+            if !self.done && self.reader.ready_state() != web_sys::FileReader::DONE {
+                self.listener.take();
                 self.reader.abort();
             }
         }
     }
+
+    /// Reads `blob` in fixed-size chunks of at most `chunk_size` bytes, calling
+    /// `on_chunk` with each chunk (or the first error) as it arrives.
+    ///
+    /// Returning `false` from `on_chunk` aborts the read. The read also stops once
+    /// the whole blob has been consumed or an error occurs.
+    pub fn read_chunks<B, F>(blob: &B, chunk_size: u64, on_chunk: F) -> ChunkReader
+    where
+        B: BlobLike,
+        F: FnMut(Result<js_sys::Uint8Array, FileReadError>) -> bool + 'static,
+    {
+        let reader = web_sys::FileReader::new().unwrap_throw();
+        let blob = blob.as_raw().clone();
+        let size = blob.size() as u64;
+
+        let inner = Rc::new(RefCell::new(ChunkReaderInner {
+            reader: reader.clone(),
+            blob,
+            size,
+            offset: 0,
+            chunk_size,
+            on_chunk: Some(Box::new(on_chunk)),
+            listener: None,
+            done: false,
+        }));
+
+        let weak: Weak<RefCell<ChunkReaderInner>> = Rc::downgrade(&inner);
+
+        let listener = EventListener::new(&reader, "loadend", move |_| {
+            if let Some(inner) = weak.upgrade() {
+                ChunkReaderInner::on_loadend(&inner);
+            }
+        });
+
+        {
+            let mut borrow = inner.borrow_mut();
+            borrow.listener = Some(listener);
+
+            if borrow.offset >= borrow.size {
+                // Nothing to read: finish immediately, dropping the consumer
+                // closure so an empty blob yields an empty — but terminating —
+                // stream.
+                borrow.stop();
+            } else {
+                borrow.read_next();
+            }
+        }
+
+        ChunkReader { inner }
+    }
+}
+
+pub mod futures {
+    use super::FileReadError;
+    use crate::blob::BlobLike;
+    use futures_channel::{mpsc, oneshot};
+    use futures_core::Stream;
+    use std::future::Future;
+    use std::pin::Pin;
+    use std::task::{Context, Poll};
+    use wasm_bindgen::UnwrapThrowExt;
+
+    /// A future that resolves with the contents of a [`BlobLike`] value.
+    ///
+    /// The future holds onto the underlying [`FileReader`](super::callbacks::FileReader),
+    /// so dropping it before it resolves cancels the read via the `abort()` logic in
+    /// `Drop`.
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct FileReadFuture<T> {
+        // Kept alive so that dropping the future aborts the in-flight read.
+        _reader: super::callbacks::FileReader,
+        receiver: oneshot::Receiver<Result<T, FileReadError>>,
+    }
+
+    impl<T> Future for FileReadFuture<T> {
+        type Output = Result<T, FileReadError>;
+
+        fn poll(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+            // The sender lives as long as the `FileReader` we hold, so it cannot be
+            // dropped without sending a value first.
+            Pin::new(&mut self.receiver)
+                .poll(cx)
+                .map(|x| x.unwrap_throw())
+        }
+    }
+
+    #[inline]
+    pub fn read_as_text<B>(blob: &B) -> FileReadFuture<String>
+    where
+        B: BlobLike,
+    {
+        let (sender, receiver) = oneshot::channel();
+        let reader = super::callbacks::read_to_string(blob, move |x| {
+            let _ = sender.send(x);
+        });
+        FileReadFuture {
+            _reader: reader,
+            receiver,
+        }
+    }
+
+    #[inline]
+    pub fn read_as_data_url<B>(blob: &B) -> FileReadFuture<String>
+    where
+        B: BlobLike,
+    {
+        let (sender, receiver) = oneshot::channel();
+        let reader = super::callbacks::read_to_data_url(blob, move |x| {
+            let _ = sender.send(x);
+        });
+        FileReadFuture {
+            _reader: reader,
+            receiver,
+        }
+    }
+
+    #[inline]
+    pub fn read_as_array_buffer<B>(blob: &B) -> FileReadFuture<js_sys::ArrayBuffer>
+    where
+        B: BlobLike,
+    {
+        let (sender, receiver) = oneshot::channel();
+        let reader = super::callbacks::read_to_array_buffer(blob, move |x| {
+            let _ = sender.send(x);
+        });
+        FileReadFuture {
+            _reader: reader,
+            receiver,
+        }
+    }
+
+    /// A [`Stream`] yielding the contents of a [`BlobLike`] value in fixed-size
+    /// chunks, created by [`read_chunks`].
+    ///
+    /// The stream holds onto the underlying
+    /// [`ChunkReader`](super::callbacks::ChunkReader), so dropping it aborts the
+    /// in-flight read.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct ChunkStream {
+        // Kept alive so that dropping the stream aborts the in-flight read.
+        _reader: super::callbacks::ChunkReader,
+        receiver: mpsc::UnboundedReceiver<Result<Vec<u8>, FileReadError>>,
+    }
+
+    impl Stream for ChunkStream {
+        type Item = Result<Vec<u8>, FileReadError>;
+
+        fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<Self::Item>> {
+            Pin::new(&mut self.receiver).poll_next(cx)
+        }
+    }
+
+    #[inline]
+    pub fn read_chunks<B>(blob: &B, chunk_size: u64) -> ChunkStream
+    where
+        B: BlobLike,
+    {
+        let (sender, receiver) = mpsc::unbounded();
+        let reader = super::callbacks::read_chunks(blob, chunk_size, move |x| {
+            // `unbounded_send` only fails once the receiver (the stream) is gone,
+            // in which case we want to abort the read.
+            sender.unbounded_send(x.map(|array| array.to_vec())).is_ok()
+        });
+        ChunkStream {
+            _reader: reader,
+            receiver,
+        }
+    }
+}
+
+pub mod sync {
+    use super::FileReadError;
+    use crate::blob::BlobLike;
+    use wasm_bindgen::{JsCast, JsValue, UnwrapThrowExt};
+
+    fn from_exception(error: JsValue) -> FileReadError {
+        FileReadError {
+            error: error.unchecked_into::<web_sys::DomException>(),
+        }
+    }
+
+    #[inline]
+    pub fn read_to_string<B>(blob: &B) -> Result<String, FileReadError>
+    where
+        B: BlobLike,
+    {
+        let reader = web_sys::FileReaderSync::new().unwrap_throw();
+        reader.read_as_text(blob.as_raw()).map_err(from_exception)
+    }
+
+    #[inline]
+    pub fn read_to_data_url<B>(blob: &B) -> Result<String, FileReadError>
+    where
+        B: BlobLike,
+    {
+        let reader = web_sys::FileReaderSync::new().unwrap_throw();
+        reader
+            .read_as_data_url(blob.as_raw())
+            .map_err(from_exception)
+    }
+
+    #[inline]
+    pub fn read_to_array_buffer<B>(blob: &B) -> Result<js_sys::ArrayBuffer, FileReadError>
+    where
+        B: BlobLike,
+    {
+        let reader = web_sys::FileReaderSync::new().unwrap_throw();
+        reader
+            .read_as_array_buffer(blob.as_raw())
+            .map_err(from_exception)
+    }
 }
@@ -0,0 +1,7 @@
+mod blob;
+mod file_list;
+mod file_reader;
+
+pub use blob::*;
+pub use file_list::*;
+pub use file_reader::{callbacks, futures, sync, FileReadError};
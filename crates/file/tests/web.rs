@@ -3,10 +3,12 @@
 #![cfg(target_arch = "wasm32")]
 use futures::unsync::oneshot::channel;
 use futures::Future;
+use futures_util::StreamExt;
 use wasm_bindgen::prelude::*;
 use wasm_bindgen_test::*;
 
 use gloo_file::callbacks::read_to_string;
+use gloo_file::futures::read_chunks;
 use gloo_file::Blob;
 
 wasm_bindgen_test_configure!(run_in_browser);
@@ -46,3 +48,24 @@ fn read_as_text_abort() {
         unreachable!();
     });
 }
+
+#[wasm_bindgen_test]
+async fn read_chunks_to_completion() {
+    let blob = Blob::new("Hello world!");
+
+    let mut out = Vec::new();
+    let mut stream = read_chunks(&blob, 4);
+    while let Some(chunk) = stream.next().await {
+        out.extend_from_slice(&chunk.unwrap_throw());
+    }
+
+    assert_eq!(out, b"Hello world!");
+}
+
+#[wasm_bindgen_test]
+async fn read_chunks_empty_blob() {
+    let blob = Blob::new("");
+
+    let mut stream = read_chunks(&blob, 4);
+    assert!(stream.next().await.is_none());
+}